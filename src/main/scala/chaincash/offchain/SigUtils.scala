@@ -4,8 +4,11 @@ import sigma.crypto.CryptoConstants
 import sigma.GroupElement
 import sigma.crypto.SecP256K1Group
 import sigma.data.{CBigInt, CGroupElement}
+import org.bouncycastle.crypto.ec.CustomNamedCurves
+import org.bouncycastle.math.ec.{ECAlgorithms, ECPoint}
 import java.security.SecureRandom
 import scala.annotation.tailrec
+import scala.util.Try
 import scorex.crypto.hash.Blake2b256
 
 object SigUtils {
@@ -45,6 +48,11 @@ object SigUtils {
     }
   }
 
+  /**
+   * Signed message to be verified: (message, public key, a, z)
+   */
+  type SignedMessage = (Array[Byte], GroupElement, GroupElement, BigInt)
+
   /**
    * Verifies a Schnorr signature
    * @param msg Message that was signed
@@ -61,4 +69,70 @@ object SigUtils {
     val rhs = a.multiply(publicKey.exp(eBigInt.bigInteger))
     lhs == rhs
   }
-}
\ No newline at end of file
+
+  private lazy val secp256k1Params = CustomNamedCurves.getByName("secp256k1")
+  private lazy val secp256k1Curve = secp256k1Params.getCurve
+
+  // sigma's EcPointType does not expose the underlying BouncyCastle point, so decode it from the encoding;
+  // sigma encodes the identity as 33 zero bytes, which BouncyCastle does not accept
+  private def toBcPoint(ge: GroupElement): ECPoint = {
+    if (CryptoConstants.dlogGroup.isIdentity(ge)) {
+      secp256k1Curve.getInfinity
+    } else {
+      secp256k1Curve.decodePoint(ge.getEncoded.toArray)
+    }
+  }
+
+  @tailrec
+  private def randNonZeroCoefficient(random: SecureRandom): BigInt = {
+    val c = BigInt(new java.math.BigInteger(128, random))
+    if (c == 0) randNonZeroCoefficient(random) else c
+  }
+
+  /**
+   * Verifies many Schnorr signatures at once via a random linear combination, checking
+   * g^(-sum(c_i * z_i)) * prod(a_i^c_i * publicKey_i^(c_i * e_i)) == identity
+   * for random nonzero 128-bit c_i, evaluated as a single multi-scalar multiplication
+   * @param sigs signed messages
+   * @return true if all the signatures are valid (with overwhelming probability),
+   *         false if some signature is invalid or has a point which can't be decoded
+   */
+  def batchVerify(sigs: Seq[SignedMessage]): Boolean = {
+    if (sigs.isEmpty) {
+      true
+    } else Try {
+      val q = SecP256K1Group.q
+      val random = new SecureRandom()
+
+      val terms = sigs.map { case (msg, publicKey, a, z) =>
+        val c = randNonZeroCoefficient(random)
+        val e = BigInt(Blake2b256(a.getEncoded.toArray ++ msg ++ publicKey.getEncoded.toArray))
+        val cz = (c * z).mod(q)
+        val pointTerms = Seq(toBcPoint(a) -> c, toBcPoint(publicKey) -> (c * e).mod(q))
+        (cz, pointTerms)
+      }
+
+      val gScalar = (-terms.map(_._1).sum).mod(q)
+      val g = secp256k1Params.getG
+      val (points, scalars) = ((g -> gScalar) +: terms.flatMap(_._2)).unzip
+
+      ECAlgorithms.sumOfMultiplies(points.toArray, scalars.map(_.bigInteger).toArray).isInfinity
+    }.getOrElse(false)
+  }
+
+  /**
+   * Batch-verifies Schnorr signatures, falling back to one-by-one verification
+   * if the batch check fails, to identify the offending signatures
+   * @param sigs signed messages
+   * @return indices of invalid signatures, empty if all of them are valid
+   */
+  def findInvalidSignatures(sigs: Seq[SignedMessage]): Seq[Int] = {
+    if (batchVerify(sigs)) {
+      Seq.empty
+    } else {
+      sigs.zipWithIndex.collect {
+        case ((msg, publicKey, a, z), idx) if !verify(msg, publicKey, a, z) => idx
+      }
+    }
+  }
+}
@@ -165,4 +165,94 @@ class SigUtilsSpec extends PropSpec with Matchers {
     results.forall(identity) shouldBe true
   }
 
+  property("SigUtils batchVerify should accept valid signatures from different keys") {
+    val sigs = (1 to 20).map { i =>
+      val message = s"Note $i".getBytes
+      val secretKey = SigUtils.randBigInt
+      val publicKey = g.exp(secretKey.bigInteger)
+      val (a, z) = SigUtils.sign(message, secretKey)
+      (message, publicKey: GroupElement, a, z)
+    }
+
+    SigUtils.batchVerify(sigs) shouldBe true
+    SigUtils.findInvalidSignatures(sigs) shouldBe empty
+  }
+
+  property("SigUtils batchVerify should accept empty batch") {
+    SigUtils.batchVerify(Seq.empty) shouldBe true
+  }
+
+  property("SigUtils batchVerify should reject batch with one invalid signature and identify it") {
+    val sigs = (1 to 10).map { i =>
+      val message = s"Note $i".getBytes
+      val secretKey = SigUtils.randBigInt
+      val publicKey = g.exp(secretKey.bigInteger)
+      val (a, z) = SigUtils.sign(message, secretKey)
+      (message, publicKey: GroupElement, a, z)
+    }
+    val (msg, pk, a, z) = sigs(7)
+    val tampered = sigs.updated(7, (msg, pk, a, z + 1))
+
+    SigUtils.batchVerify(tampered) shouldBe false
+    SigUtils.findInvalidSignatures(tampered) shouldBe Seq(7)
+  }
+
+  property("SigUtils batchVerify should reject tampered signatures cancelling out in unweighted sum") {
+    val sigs = (1 to 10).map { i =>
+      val message = s"Note $i".getBytes
+      val secretKey = SigUtils.randBigInt
+      val publicKey = g.exp(secretKey.bigInteger)
+      val (a, z) = SigUtils.sign(message, secretKey)
+      (message, publicKey: GroupElement, a, z)
+    }
+    val (msg3, pk3, a3, z3) = sigs(3)
+    val (msg5, pk5, a5, z5) = sigs(5)
+    val tampered = sigs
+      .updated(3, (msg3, pk3, a3, z3 + 1))
+      .updated(5, (msg5, pk5, a5, z5 - 1))
+
+    SigUtils.batchVerify(tampered) shouldBe false
+    SigUtils.findInvalidSignatures(tampered) shouldBe Seq(3, 5)
+  }
+
+  property("SigUtils batchVerify should reject batch with tampered message or public key") {
+    val sigs = (1 to 10).map { i =>
+      val message = s"Note $i".getBytes
+      val secretKey = SigUtils.randBigInt
+      val publicKey = g.exp(secretKey.bigInteger)
+      val (a, z) = SigUtils.sign(message, secretKey)
+      (message, publicKey: GroupElement, a, z)
+    }
+    val (_, pk2, a2, z2) = sigs(2)
+    val (msg6, _, a6, z6) = sigs(6)
+    val otherPublicKey: GroupElement = g.exp(SigUtils.randBigInt.bigInteger)
+
+    val wrongMessage = sigs.updated(2, ("Other note".getBytes, pk2, a2, z2))
+    SigUtils.batchVerify(wrongMessage) shouldBe false
+    SigUtils.findInvalidSignatures(wrongMessage) shouldBe Seq(2)
+
+    val wrongKey = sigs.updated(6, (msg6, otherPublicKey, a6, z6))
+    SigUtils.batchVerify(wrongKey) shouldBe false
+    SigUtils.findInvalidSignatures(wrongKey) shouldBe Seq(6)
+  }
+
+  property("SigUtils batchVerify should reject identity points without throwing") {
+    val sigs = (1 to 10).map { i =>
+      val message = s"Note $i".getBytes
+      val secretKey = SigUtils.randBigInt
+      val publicKey = g.exp(secretKey.bigInteger)
+      val (a, z) = SigUtils.sign(message, secretKey)
+      (message, publicKey: GroupElement, a, z)
+    }
+    val identity: GroupElement = CryptoConstants.dlogGroup.identity
+    val (msg1, pk1, _, z1) = sigs(1)
+    val (msg4, _, a4, z4) = sigs(4)
+    val tampered = sigs
+      .updated(1, (msg1, pk1, identity, z1))
+      .updated(4, (msg4, identity, a4, z4))
+
+    SigUtils.batchVerify(tampered) shouldBe false
+    SigUtils.findInvalidSignatures(tampered) shouldBe Seq(1, 4)
+  }
+
 }